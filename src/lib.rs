@@ -25,9 +25,19 @@ pub struct CommandError {
 /// Describes the particular kinds of errors that may occur while running a command
 #[derive(thiserror::Error, Debug)]
 pub enum CommandErrorKind {
-    /// The child process was terminated, so did not exit successfully
-    #[error("Child process was terminated and has no exit code")]
-    Terminated,
+    /// The child process was terminated, so did not exit successfully.
+    ///
+    /// On Unix `signal` carries the signal number that killed the child (from
+    /// [`std::os::unix::process::ExitStatusExt::signal`]), letting callers tell
+    /// an OOM-kill (`SIGKILL`) apart from a graceful `SIGTERM`. It is `None` on
+    /// non-Unix targets or when no signal information is available.
+    #[error("Child process was {}", describe_signal(*.signal))]
+    Terminated {
+        /// The signal that terminated the child, if known
+        signal: Option<i32>,
+        /// The output captured before termination
+        output: Output,
+    },
     /// The child process completed with a non-zero exit code
     #[error("\
 Child process completed with non-zero exit code {0}
@@ -40,8 +50,31 @@ Child process completed with non-zero exit code {0}
     #[error("An error occurred while invoking child process")]
     IoError(#[from] std::io::Error),
 
+    /// A connectivity failure was detected in the command's `stderr`
     #[error("An error occurred while trying to connect")]
     ConnectivityError(CommandConnectivityError),
+
+    /// The child process did not exit within the allotted time and was killed
+    #[error("\
+Child process did not complete within {0:?} and was terminated
+  stdout: {}
+  stderr: {}",
+        String::from_utf8_lossy(&.1.stdout).to_string(),
+        String::from_utf8_lossy(&.1.stderr).to_string())]
+    TimedOut(std::time::Duration, Output),
+
+    /// A registered [`StderrClassifier`] recognised a known failure condition
+    #[error("command failed ({0}):\n{1}")]
+    Diagnosed(&'static str, String),
+
+    /// A stage of a [`Pipeline`] failed; `stage` is its zero-based index
+    #[error("pipeline stage {stage} failed")]
+    PipelineStageError {
+        /// Zero-based index of the stage that failed
+        stage: usize,
+        /// The error produced by that stage's own classification
+        source: Box<CommandError>,
+    },
 }
 
 /// Adds useful extension methods to the `Command` type
@@ -127,6 +160,233 @@ pub trait CommandExt {
     /// assert_eq!(error.command, "foobar");
     /// ```
     fn result(&mut self) -> CommandResult;
+    /// Runs the command, streaming `stdout` and `stderr` to a callback as data
+    /// arrives while still accumulating the full output into the returned
+    /// `Output`.
+    ///
+    /// The `on_chunk` callback is invoked with a [`StreamSource`] tag and the
+    /// bytes read from that stream each time one of the pipes becomes readable,
+    /// which lets callers observe the progress of long-running tools (for
+    /// example `helm install` or `kubectl apply`) instead of waiting for the
+    /// process to exit. Once the child completes, the collected bytes are run
+    /// through the same classification as [`CommandExt::result`], so exit,
+    /// connectivity and termination errors are reported identically.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::process::Command;
+    /// use fluvio_command::{CommandExt, StreamSource};
+    ///
+    /// let mut streamed = Vec::new();
+    /// let output = Command::new("echo")
+    ///     .arg("hello")
+    ///     .result_streaming(|source, bytes| {
+    ///         if let StreamSource::Stdout = source {
+    ///             streamed.extend_from_slice(bytes);
+    ///         }
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(output.stdout, b"hello\n");
+    /// assert_eq!(streamed, b"hello\n");
+    /// ```
+    fn result_streaming<F>(&mut self, on_chunk: F) -> CommandResult
+    where
+        F: FnMut(StreamSource, &[u8]) + Send;
+    /// Runs the command but gives up after `timeout`, killing the child and
+    /// returning [`CommandErrorKind::TimedOut`].
+    ///
+    /// Commands like `helm` or `kubectl` run against an unreachable cluster can
+    /// hang indefinitely instead of returning a diagnosable stderr, so this
+    /// bounds their execution. When the deadline elapses the child is killed and
+    /// reaped, and whatever `stdout`/`stderr` was captured up to that point is
+    /// attached to the error so callers can still diagnose. A process that exits
+    /// before the deadline is classified exactly as [`CommandExt::result`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::process::Command;
+    /// use std::time::Duration;
+    /// use fluvio_command::{CommandExt, CommandErrorKind};
+    ///
+    /// let error = Command::new("sleep")
+    ///     .arg("10")
+    ///     .result_timeout(Duration::from_millis(100))
+    ///     .unwrap_err();
+    /// assert!(matches!(error.source, CommandErrorKind::TimedOut(_, _)));
+    /// ```
+    fn result_timeout(&mut self, timeout: std::time::Duration) -> CommandResult;
+    /// Attach a set of [`StderrClassifier`]s to this command.
+    ///
+    /// The returned [`ClassifiedCommand`] runs the command with
+    /// [`ClassifiedCommand::result`], consulting the classifiers against the
+    /// child's `stderr` on a non-zero exit before falling back to
+    /// [`CommandErrorKind::ExitError`]. This lets callers diagnose a range of
+    /// known failure conditions (unreachable cluster, auth/forbidden, release
+    /// already exists, missing context) without re-parsing stderr themselves,
+    /// and register their own classifiers alongside the built-ins from
+    /// [`default_classifiers`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::process::Command;
+    /// use fluvio_command::{CommandExt, CommandErrorKind, default_classifiers};
+    ///
+    /// let error = Command::new("bash")
+    ///     .args(&["-c", r#"echo "Error: cannot re-use a name that is still in use" 1>&2 && exit 1"#])
+    ///     .with_classifiers(default_classifiers())
+    ///     .result()
+    ///     .unwrap_err();
+    /// assert!(matches!(error.source, CommandErrorKind::Diagnosed("release-already-exists", _)));
+    /// ```
+    fn with_classifiers(
+        &mut self,
+        classifiers: Vec<Box<dyn StderrClassifier>>,
+    ) -> ClassifiedCommand<'_>;
+    /// Runs the command on a blocking thread pool and awaits its completion,
+    /// yielding the same [`CommandResult`] as [`CommandExt::result`].
+    ///
+    /// This is gated behind the `async` feature. Because running a child
+    /// process blocks, the work is dispatched to the runtime's blocking pool so
+    /// callers in an async service can drive many external commands concurrently
+    /// without stalling the executor. The error model is shared with the
+    /// synchronous path, so downstream code matches on the same
+    /// [`CommandErrorKind`] regardless of which entry point produced it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # async fn run() {
+    /// use std::process::Command;
+    /// use fluvio_command::CommandExt;
+    ///
+    /// let output = Command::new("true").result_async().await.unwrap();
+    /// assert!(output.status.success());
+    /// # }
+    /// ```
+    #[cfg(feature = "async")]
+    fn result_async(self) -> impl std::future::Future<Output = CommandResult> + Send;
+}
+
+/// Inspects a command's `stderr` and, if it recognises a known failure
+/// condition, reports it as a specific [`CommandErrorKind`].
+///
+/// Implementors are consulted by [`ClassifiedCommand::result`] in registration
+/// order; the first classifier to return `Some` wins.
+pub trait StderrClassifier: Send + Sync {
+    /// Inspect `stderr`, returning the error kind to report if it matches.
+    fn classify(&self, stderr: &str) -> Option<CommandErrorKind>;
+}
+
+/// Recognises the "Kubernetes cluster unreachable" condition from helm/kubectl.
+pub struct UnreachableClusterClassifier;
+impl StderrClassifier for UnreachableClusterClassifier {
+    fn classify(&self, stderr: &str) -> Option<CommandErrorKind> {
+        if stderr.contains("Kubernetes cluster unreachable") {
+            error!("kubernetes cluster unreachable");
+            return Some(CommandErrorKind::ConnectivityError(
+                CommandConnectivityError::Error(stderr.to_string()),
+            ));
+        }
+        None
+    }
+}
+
+/// Recognises authentication/authorization failures from the Kubernetes API.
+pub struct AuthForbiddenClassifier;
+impl StderrClassifier for AuthForbiddenClassifier {
+    fn classify(&self, stderr: &str) -> Option<CommandErrorKind> {
+        if stderr.contains("Unauthorized")
+            || stderr.contains("forbidden")
+            || stderr.contains("is forbidden")
+        {
+            return Some(CommandErrorKind::Diagnosed(
+                "auth/forbidden",
+                stderr.to_string(),
+            ));
+        }
+        None
+    }
+}
+
+/// Recognises a helm release that already exists under the requested name.
+pub struct ReleaseAlreadyExistsClassifier;
+impl StderrClassifier for ReleaseAlreadyExistsClassifier {
+    fn classify(&self, stderr: &str) -> Option<CommandErrorKind> {
+        if stderr.contains("cannot re-use a name that is still in use")
+            || (stderr.contains("release named") && stderr.contains("already exists"))
+        {
+            return Some(CommandErrorKind::Diagnosed(
+                "release-already-exists",
+                stderr.to_string(),
+            ));
+        }
+        None
+    }
+}
+
+/// Recognises a missing or misspelled kube-context.
+pub struct ContextNotFoundClassifier;
+impl StderrClassifier for ContextNotFoundClassifier {
+    fn classify(&self, stderr: &str) -> Option<CommandErrorKind> {
+        if stderr.contains("context was not found")
+            || stderr.contains("no context exists with the name")
+        {
+            return Some(CommandErrorKind::Diagnosed(
+                "context-not-found",
+                stderr.to_string(),
+            ));
+        }
+        None
+    }
+}
+
+/// Returns the built-in classifiers for common helm/kubectl failure conditions.
+pub fn default_classifiers() -> Vec<Box<dyn StderrClassifier>> {
+    vec![
+        Box::new(UnreachableClusterClassifier),
+        Box::new(AuthForbiddenClassifier),
+        Box::new(ReleaseAlreadyExistsClassifier),
+        Box::new(ContextNotFoundClassifier),
+    ]
+}
+
+/// A command paired with a set of [`StderrClassifier`]s, produced by
+/// [`CommandExt::with_classifiers`].
+pub struct ClassifiedCommand<'a> {
+    command: &'a mut osCommand,
+    classifiers: Vec<Box<dyn StderrClassifier>>,
+}
+
+impl ClassifiedCommand<'_> {
+    /// Runs the command, consulting the attached classifiers on failure.
+    ///
+    /// Behaves like [`CommandExt::result`] but, on a non-zero exit, each
+    /// classifier is offered the child's `stderr` in order; the first to return
+    /// a [`CommandErrorKind`] determines the reported error.
+    pub fn result(&mut self) -> CommandResult {
+        let command_line = self.command.display();
+        info!("executing command {}", command_line);
+        self.command
+            .output()
+            .map_err(|e| CommandError {
+                command: command_line.clone(),
+                source: CommandErrorKind::IoError(e),
+            })
+            .and_then(|output| classify_output(&command_line, output, &self.classifiers))
+    }
+}
+
+/// Identifies which of a child process's streams produced a chunk of data in
+/// [`CommandExt::result_streaming`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamSource {
+    /// The chunk was read from the child's standard output.
+    Stdout,
+    /// The chunk was read from the child's standard error.
+    Stderr,
 }
 
 impl CommandExt for osCommand {
@@ -159,47 +419,489 @@ impl CommandExt for osCommand {
 
     fn result(&mut self) -> CommandResult {
         info!("executing command {}", self.display());
+        let command_line = self.display();
         self.output()
             .map_err(|e| CommandError {
-                command: self.display(),
+                command: command_line.clone(),
+                source: CommandErrorKind::IoError(e),
+            })
+            .and_then(|output| classify_output(&command_line, output, &[]))
+    }
+
+    fn result_streaming<F>(&mut self, mut on_chunk: F) -> CommandResult
+    where
+        F: FnMut(StreamSource, &[u8]) + Send,
+    {
+        use std::process::Stdio;
+        use std::sync::Mutex;
+
+        let command_line = self.display();
+        info!("executing command (streaming) {}", command_line);
+
+        let mut child = self
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| CommandError {
+                command: command_line.clone(),
+                source: CommandErrorKind::IoError(e),
+            })?;
+
+        let mut stdout = child.stdout.take();
+        let mut stderr = child.stderr.take();
+
+        // Drain both pipes concurrently so that a child which writes a lot to
+        // one stream can't deadlock by filling its pipe buffer while we block
+        // on the other. One reader thread per pipe shares the user callback
+        // behind a `Mutex` so chunks are delivered one at a time.
+        let callback = Mutex::new(&mut on_chunk);
+        let mut stdout_buf = Vec::new();
+        let mut stderr_buf = Vec::new();
+
+        let read_result = std::thread::scope(|scope| -> std::io::Result<()> {
+            let callback = &callback;
+            let stdout_handle = scope.spawn(move || -> std::io::Result<Vec<u8>> {
+                let mut buf = Vec::new();
+                if let Some(stream) = stdout.as_mut() {
+                    drain_stream(stream, StreamSource::Stdout, &mut buf, callback)?;
+                }
+                Ok(buf)
+            });
+            let stderr_handle = scope.spawn(move || -> std::io::Result<Vec<u8>> {
+                let mut buf = Vec::new();
+                if let Some(stream) = stderr.as_mut() {
+                    drain_stream(stream, StreamSource::Stderr, &mut buf, callback)?;
+                }
+                Ok(buf)
+            });
+            stdout_buf = stdout_handle.join().expect("stdout reader panicked")?;
+            stderr_buf = stderr_handle.join().expect("stderr reader panicked")?;
+            Ok(())
+        });
+
+        if let Err(e) = read_result {
+            return Err(CommandError {
+                command: command_line,
+                source: CommandErrorKind::IoError(e),
+            });
+        }
+
+        let status = child.wait().map_err(|e| CommandError {
+            command: command_line.clone(),
+            source: CommandErrorKind::IoError(e),
+        })?;
+
+        classify_output(
+            &command_line,
+            Output {
+                status,
+                stdout: stdout_buf,
+                stderr: stderr_buf,
+            },
+            &[],
+        )
+    }
+
+    fn result_timeout(&mut self, timeout: std::time::Duration) -> CommandResult {
+        use std::process::Stdio;
+        use std::sync::{Arc, Mutex};
+        use std::time::{Duration, Instant};
+
+        let command_line = self.display();
+        info!("executing command (timeout {:?}) {}", timeout, command_line);
+
+        let mut child = self
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| CommandError {
+                command: command_line.clone(),
                 source: CommandErrorKind::IoError(e),
+            })?;
+
+        // Drain the pipes on background threads into shared buffers so the child
+        // can't deadlock on a full pipe while we poll, and so the captured bytes
+        // are available whether the child exits cleanly or is killed.
+        let stdout_buf = Arc::new(Mutex::new(Vec::new()));
+        let stderr_buf = Arc::new(Mutex::new(Vec::new()));
+        let readers = [
+            child.stdout.take().map(|s| {
+                let buf = Arc::clone(&stdout_buf);
+                std::thread::spawn(move || collect_stream(s, &buf))
+            }),
+            child.stderr.take().map(|s| {
+                let buf = Arc::clone(&stderr_buf);
+                std::thread::spawn(move || collect_stream(s, &buf))
+            }),
+        ];
+
+        let deadline = Instant::now() + timeout;
+        let poll_interval = Duration::from_millis(10);
+        let timed_out = loop {
+            match child.try_wait() {
+                Ok(Some(_)) => break false,
+                Ok(None) => {
+                    if Instant::now() >= deadline {
+                        let _ = child.kill();
+                        let _ = child.wait();
+                        break true;
+                    }
+                    std::thread::sleep(poll_interval.min(deadline.saturating_duration_since(Instant::now())).max(Duration::from_millis(1)));
+                }
+                Err(e) => {
+                    return Err(CommandError {
+                        command: command_line,
+                        source: CommandErrorKind::IoError(e),
+                    });
+                }
+            }
+        };
+
+        // Collect whatever the readers captured. A reader finishes once its pipe
+        // reaches EOF, which normally happens as soon as the child exits — but
+        // `kill()` only signals the direct child, so a leaked descendant holding
+        // the write end can keep a pipe open forever. Bound the wait with a short
+        // grace period and then detach any straggler rather than block the call.
+        let grace = Instant::now() + Duration::from_millis(100);
+        for reader in readers.into_iter().flatten() {
+            while !reader.is_finished() && Instant::now() < grace {
+                std::thread::sleep(Duration::from_millis(5));
+            }
+            if reader.is_finished() {
+                let _ = reader.join();
+            }
+        }
+        // Snapshot the shared buffers without requiring sole ownership: a
+        // detached straggler may still hold an `Arc`, so clone the contents.
+        let stdout = stdout_buf.lock().map(|b| b.clone()).unwrap_or_default();
+        let stderr = stderr_buf.lock().map(|b| b.clone()).unwrap_or_default();
+
+        if timed_out {
+            error!("command timed out after {:?}: {}", timeout, command_line);
+            let status = child.wait().map_err(|e| CommandError {
+                command: command_line.clone(),
+                source: CommandErrorKind::IoError(e),
+            })?;
+            return Err(CommandError {
+                command: command_line,
+                source: CommandErrorKind::TimedOut(
+                    timeout,
+                    Output {
+                        status,
+                        stdout,
+                        stderr,
+                    },
+                ),
+            });
+        }
+
+        let status = child.wait().map_err(|e| CommandError {
+            command: command_line.clone(),
+            source: CommandErrorKind::IoError(e),
+        })?;
+        classify_output(
+            &command_line,
+            Output {
+                status,
+                stdout,
+                stderr,
+            },
+            &[],
+        )
+    }
+
+    fn with_classifiers(
+        &mut self,
+        classifiers: Vec<Box<dyn StderrClassifier>>,
+    ) -> ClassifiedCommand<'_> {
+        ClassifiedCommand {
+            command: self,
+            classifiers,
+        }
+    }
+
+    #[cfg(feature = "async")]
+    fn result_async(mut self) -> impl std::future::Future<Output = CommandResult> + Send {
+        // Driving a child process blocks, so hand it to the runtime's blocking
+        // pool and reuse the synchronous classification verbatim.
+        fluvio_future::task::spawn_blocking(move || self.result())
+    }
+}
+
+/// Reads `stream` to EOF, appending everything into the shared buffer. Used by
+/// the timeout path, which must capture whatever was produced before a kill.
+fn collect_stream<R: std::io::Read>(mut stream: R, buf: &std::sync::Mutex<Vec<u8>>) {
+    let mut chunk = [0u8; 4096];
+    loop {
+        match stream.read(&mut chunk) {
+            Ok(0) | Err(_) => return,
+            Ok(read) => {
+                if let Ok(mut buf) = buf.lock() {
+                    buf.extend_from_slice(&chunk[..read]);
+                }
+            }
+        }
+    }
+}
+
+/// Runs a sequence of commands as a connected pipe, wiring each stage's
+/// `stdout` into the next stage's `stdin` (`a | b | c`).
+///
+/// Each stage is still run through the same classification as
+/// [`CommandExt::result`], so a failing stage surfaces its connectivity or exit
+/// error wrapped in [`CommandErrorKind::PipelineStageError`] identifying which
+/// stage failed. When every stage succeeds, the final stage's captured
+/// `stdout`/`stderr` become the returned [`Output`].
+///
+/// # Example
+///
+/// ```
+/// use std::process::Command;
+/// use fluvio_command::Pipeline;
+///
+/// let mut echo = Command::new("echo");
+/// echo.arg("hello world");
+/// let mut upper = Command::new("tr");
+/// upper.args(&["a-z", "A-Z"]);
+///
+/// let output = Pipeline::new()
+///     .command(echo)
+///     .command(upper)
+///     .result()
+///     .unwrap();
+/// assert_eq!(output.stdout, b"HELLO WORLD\n");
+/// ```
+#[derive(Default)]
+pub struct Pipeline {
+    commands: Vec<osCommand>,
+}
+
+impl Pipeline {
+    /// Creates an empty pipeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a command as the next stage of the pipeline.
+    pub fn command(mut self, command: osCommand) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Runs every stage connected end to end and returns the final stage's
+    /// output, or the first stage that fails.
+    pub fn result(self) -> CommandResult {
+        use std::process::Stdio;
+
+        let stage_count = self.commands.len();
+        if stage_count == 0 {
+            return Err(CommandError {
+                command: String::from("<empty pipeline>"),
+                source: CommandErrorKind::IoError(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "pipeline has no stages",
+                )),
+            });
+        }
+
+        // Spawn every stage up front, handing each child's stdout to the next
+        // stage's stdin. Every stage's stderr is captured for classification.
+        let mut children = Vec::with_capacity(stage_count);
+        let mut prev_stdout = None;
+        for (stage, mut command) in self.commands.into_iter().enumerate() {
+            if let Some(stdout) = prev_stdout.take() {
+                command.stdin(Stdio::from(stdout));
+            }
+            command.stdout(Stdio::piped()).stderr(Stdio::piped());
+            let command_line = command.display();
+            let mut child = command.spawn().map_err(|e| CommandError {
+                command: String::from("<pipeline>"),
+                source: CommandErrorKind::PipelineStageError {
+                    stage,
+                    source: Box::new(CommandError {
+                        command: command_line.clone(),
+                        source: CommandErrorKind::IoError(e),
+                    }),
+                },
+            })?;
+            // Intermediate stages feed the next stage's stdin; only the final
+            // stage keeps its stdout for capture.
+            if stage + 1 < stage_count {
+                prev_stdout = child.stdout.take();
+            }
+            children.push((command_line, child));
+        }
+
+        // Drain every stage concurrently: each `wait_with_output` runs on its
+        // own thread so the final stage's stdout is read as it is produced.
+        // Reading all stages in series here would deadlock `a | b | c` whenever
+        // the last stage emits more than one pipe buffer, because the unread
+        // stdout would stall the last stage, which would stall its upstream.
+        let waiters: Vec<_> = children
+            .into_iter()
+            .enumerate()
+            .map(|(stage, (command_line, child))| {
+                std::thread::spawn(move || (stage, command_line, child.wait_with_output()))
             })
-            .and_then(|output| match output.status.code() {
-                Some(0i32) => Ok(output),
-                None => {
-                    error!(
-                        "command error occured with {}. a command error kind occured",
-                        self.display()
-                    );
+            .collect();
+
+        // Collect results indexed by stage so classification stays ordered.
+        let mut outputs: Vec<Option<(String, std::io::Result<Output>)>> =
+            (0..stage_count).map(|_| None).collect();
+        for waiter in waiters {
+            let (stage, command_line, output) = waiter.join().expect("pipeline waiter panicked");
+            outputs[stage] = Some((command_line, output));
+        }
+
+        let mut final_output = None;
+        for (stage, slot) in outputs.into_iter().enumerate() {
+            let (command_line, output) = slot.expect("every stage reports a result");
+            let output = output.map_err(|e| CommandError {
+                command: String::from("<pipeline>"),
+                source: CommandErrorKind::PipelineStageError {
+                    stage,
+                    source: Box::new(CommandError {
+                        command: command_line.clone(),
+                        source: CommandErrorKind::IoError(e),
+                    }),
+                },
+            })?;
+            match classify_output(&command_line, output, &[]) {
+                Ok(output) => final_output = Some(output),
+                Err(source) => {
                     return Err(CommandError {
-                        command: self.display(),
-                        source: CommandErrorKind::Terminated,
+                        command: String::from("<pipeline>"),
+                        source: CommandErrorKind::PipelineStageError {
+                            stage,
+                            source: Box::new(source),
+                        },
                     });
                 }
-                Some(code) => {
-                    error!(
-                        "an error occured with command {:?}, code {:?} and output {:?}",
-                        self.display(),
-                        code,
-                        output
-                    );
-                    if let Err(helm_error) = check_connectivity_error(output.stderr.clone()) {
-                        return Err(CommandError {
-                            command: self.display(),
-                            source: CommandErrorKind::ConnectivityError(helm_error),
-                        });
-                    }
+            }
+        }
+
+        Ok(final_output.expect("non-empty pipeline always yields a final stage"))
+    }
+}
+
+/// Reads `stream` to EOF in chunks, appending each chunk to `buf` and firing the
+/// shared callback so the caller observes output as it arrives.
+fn drain_stream<R, F>(
+    stream: &mut R,
+    source: StreamSource,
+    buf: &mut Vec<u8>,
+    callback: &std::sync::Mutex<&mut F>,
+) -> std::io::Result<()>
+where
+    R: std::io::Read,
+    F: FnMut(StreamSource, &[u8]) + Send,
+{
+    let mut chunk = [0u8; 4096];
+    loop {
+        let read = stream.read(&mut chunk)?;
+        if read == 0 {
+            return Ok(());
+        }
+        buf.extend_from_slice(&chunk[..read]);
+        let mut callback = callback.lock().expect("stream callback mutex poisoned");
+        callback(source, &chunk[..read]);
+    }
+}
+
+/// Classifies a finished child process's `Output` into a `CommandResult`,
+/// applying the same exit, connectivity and termination rules regardless of how
+/// the process was driven.
+///
+/// On a non-zero exit the registered `classifiers` are consulted first; if none
+/// matches, the hardcoded connectivity check is applied for backwards
+/// compatibility before falling back to [`CommandErrorKind::ExitError`].
+fn classify_output(
+    command_line: &str,
+    output: Output,
+    classifiers: &[Box<dyn StderrClassifier>],
+) -> CommandResult {
+    match output.status.code() {
+        Some(0i32) => Ok(output),
+        None => {
+            let signal = terminating_signal(&output);
+            error!(
+                "command {} was terminated ({})",
+                command_line,
+                describe_signal(signal)
+            );
+            Err(CommandError {
+                command: command_line.to_string(),
+                source: CommandErrorKind::Terminated { signal, output },
+            })
+        }
+        Some(code) => {
+            error!(
+                "an error occured with command {:?}, code {:?} and output {:?}",
+                command_line, code, output
+            );
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            for classifier in classifiers {
+                if let Some(source) = classifier.classify(&stderr) {
                     return Err(CommandError {
-                        command: self.display(),
-                        source: CommandErrorKind::ExitError(code, output),
+                        command: command_line.to_string(),
+                        source,
                     });
                 }
+            }
+            if let Err(helm_error) = check_connectivity_error(output.stderr.clone()) {
+                return Err(CommandError {
+                    command: command_line.to_string(),
+                    source: CommandErrorKind::ConnectivityError(helm_error),
+                });
+            }
+            Err(CommandError {
+                command: command_line.to_string(),
+                source: CommandErrorKind::ExitError(code, output),
             })
+        }
     }
 }
 
+/// Extracts the signal that terminated `output`'s process on Unix.
+#[cfg(unix)]
+fn terminating_signal(output: &Output) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+    output.status.signal()
+}
+
+/// On non-Unix targets there is no signal information for a terminated process.
+#[cfg(not(unix))]
+fn terminating_signal(_output: &Output) -> Option<i32> {
+    None
+}
+
+/// Renders a terminating signal as a human-readable phrase for error messages.
+fn describe_signal(signal: Option<i32>) -> String {
+    match signal {
+        Some(signal) => {
+            let name = match signal {
+                1 => Some("SIGHUP"),
+                2 => Some("SIGINT"),
+                6 => Some("SIGABRT"),
+                9 => Some("SIGKILL"),
+                11 => Some("SIGSEGV"),
+                13 => Some("SIGPIPE"),
+                15 => Some("SIGTERM"),
+                _ => None,
+            };
+            match name {
+                Some(name) => format!("terminated by signal {signal} ({name})"),
+                None => format!("terminated by signal {signal}"),
+            }
+        }
+        None => String::from("terminated and has no exit code"),
+    }
+}
+
+/// Describes a connectivity failure detected in a command's `stderr`
 #[derive(Debug)]
 pub enum CommandConnectivityError {
+    /// The offending `stderr` captured from the command
     Error(String),
 }
 
@@ -234,16 +936,87 @@ mod tests {
 
     #[test]
     fn test_output_print() {
-        let error = osCommand::new("ls").arg("does-not-exist").print();
+        osCommand::new("ls").arg("does-not-exist").print();
     }
 
     #[test]
     fn test_output_new() {
-        let error = osCommand::new("ls").arg("does-not-exist").new();
+        osCommand::new("ls").arg("does-not-exist");
     }
 
     #[test]
     fn test_output_result() {
-        let error = osCommand::new("ls").arg("does-not-exist").result();
+        let _ = osCommand::new("ls").arg("does-not-exist").result();
+    }
+
+    #[test]
+    fn test_result_streaming_accumulates_and_streams() {
+        let mut streamed = Vec::new();
+        let output = osCommand::new("echo")
+            .arg("hello world")
+            .result_streaming(|source, bytes| {
+                if let StreamSource::Stdout = source {
+                    streamed.extend_from_slice(bytes);
+                }
+            })
+            .unwrap();
+        assert_eq!(output.stdout, b"hello world\n");
+        assert_eq!(streamed, b"hello world\n");
+    }
+
+    #[test]
+    fn test_result_timeout_kills_slow_child() {
+        use std::time::Duration;
+        let error = osCommand::new("sleep")
+            .arg("10")
+            .result_timeout(Duration::from_millis(100))
+            .unwrap_err();
+        assert!(matches!(error.source, CommandErrorKind::TimedOut(_, _)));
+    }
+
+    #[test]
+    fn test_with_classifiers_diagnoses_stderr() {
+        let error = osCommand::new("bash")
+            .args([
+                "-c",
+                r#"echo "Error from server (Forbidden): pods is forbidden" 1>&2 && exit 1"#,
+            ])
+            .with_classifiers(default_classifiers())
+            .result()
+            .unwrap_err();
+        assert!(matches!(
+            error.source,
+            CommandErrorKind::Diagnosed("auth/forbidden", _)
+        ));
+    }
+
+    #[test]
+    fn test_pipeline_connects_stages() {
+        let mut echo = osCommand::new("echo");
+        echo.arg("hello world");
+        let mut upper = osCommand::new("tr");
+        upper.args(["a-z", "A-Z"]);
+        let output = Pipeline::new()
+            .command(echo)
+            .command(upper)
+            .result()
+            .unwrap();
+        assert_eq!(output.stdout, b"HELLO WORLD\n");
+    }
+
+    #[test]
+    fn test_pipeline_reports_failing_stage() {
+        let mut fail = osCommand::new("bash");
+        fail.args(["-c", "exit 3"]);
+        let cat = osCommand::new("cat");
+        let error = Pipeline::new()
+            .command(fail)
+            .command(cat)
+            .result()
+            .unwrap_err();
+        assert!(matches!(
+            error.source,
+            CommandErrorKind::PipelineStageError { stage: 0, .. }
+        ));
     }
 }